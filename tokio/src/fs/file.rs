@@ -0,0 +1,89 @@
+use crate::blocking::spawn_blocking;
+use crate::fs::asyncify;
+use crate::io::AsyncSeek;
+use crate::task::JoinHandle;
+
+use std::fmt;
+use std::fs::File as StdFile;
+use std::future::Future;
+use std::io::{self, Seek, SeekFrom};
+use std::path::Path;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A reference to an open file on the filesystem.
+///
+/// This `File` does not yet mirror the full `std::fs::File` surface; today it
+/// only supports opening a file and seeking within it asynchronously. Unlike
+/// [`fs::read`](super::read), which dispatches a single one-shot blocking
+/// call via [`asyncify`], seeking needs the two-phase `start_seek`/
+/// `poll_complete` protocol, so the blocking [`std::io::Seek`] call is
+/// dispatched directly with [`spawn_blocking`] instead.
+pub struct File {
+    std: State,
+}
+
+enum State {
+    Idle(Option<StdFile>),
+    Busy(JoinHandle<(StdFile, io::Result<u64>)>),
+}
+
+impl File {
+    /// Opens a file in read-only mode.
+    ///
+    /// See [`std::fs::File::open`] for details.
+    pub async fn open(path: impl AsRef<Path>) -> io::Result<File> {
+        let path = path.as_ref().to_owned();
+        let std = asyncify(move || StdFile::open(path)).await?;
+        Ok(File::from_std(std))
+    }
+
+    /// Converts a [`std::fs::File`] to a tokio [`File`].
+    pub fn from_std(std: StdFile) -> File {
+        File {
+            std: State::Idle(Some(std)),
+        }
+    }
+}
+
+impl AsyncSeek for File {
+    fn start_seek(self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()> {
+        let me = self.get_mut();
+        match me.std {
+            State::Busy(_) => Err(io::Error::new(
+                io::ErrorKind::Other,
+                "other file operation is pending, call poll_complete before start_seek",
+            )),
+            State::Idle(ref mut std_file) => {
+                let mut std_file = std_file.take().unwrap();
+                me.std = State::Busy(spawn_blocking(move || {
+                    let res = std_file.seek(position);
+                    (std_file, res)
+                }));
+                Ok(())
+            }
+        }
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        let me = self.get_mut();
+        match me.std {
+            State::Idle(_) => Poll::Ready(Err(io::Error::new(
+                io::ErrorKind::Other,
+                "`poll_complete` called before a seek was started with `start_seek`",
+            ))),
+            State::Busy(ref mut rx) => {
+                let (std_file, res) = ready!(Pin::new(rx).poll(cx))
+                    .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+                me.std = State::Idle(Some(std_file));
+                Poll::Ready(res)
+            }
+        }
+    }
+}
+
+impl fmt::Debug for File {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("File").finish()
+    }
+}
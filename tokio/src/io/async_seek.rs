@@ -0,0 +1,75 @@
+use std::io;
+use std::io::SeekFrom;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Seek bytes asynchronously.
+///
+/// This trait is analogous to the [`std::io::Seek`] trait, but non-blocking
+/// operations, like on [`AsyncRead`](super::AsyncRead) and
+/// [`AsyncWrite`](super::AsyncWrite), return a poll rather than blocking the
+/// calling thread.
+///
+/// Seeking is performed in two steps: first a seek is started with
+/// [`start_seek`](AsyncSeek::start_seek), which enqueues a target position;
+/// then [`poll_complete`](AsyncSeek::poll_complete) is called, potentially
+/// multiple times, to drive the seek to completion and obtain the new
+/// absolute position in the stream. It is an error to call `start_seek`
+/// before a previously started seek has been completed via `poll_complete`.
+pub trait AsyncSeek {
+    /// Attempts to seek to an offset, in bytes, in a stream.
+    ///
+    /// A seek beyond the end of a stream is allowed, but behavior is defined
+    /// by the implementation.
+    ///
+    /// This method does not return the new position, `poll_complete` must be
+    /// called to obtain it.
+    ///
+    /// # Errors
+    ///
+    /// This function can return an error if the other method before it has
+    /// not yet completed, i.e. the last call to `start_seek` or
+    /// `poll_complete` did not finish with `Poll::Ready`.
+    fn start_seek(self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()>;
+
+    /// Waits for a seek operation to complete.
+    ///
+    /// If the seek operation completed successfully, this method returns the
+    /// new position from the start of the stream. That position can be used
+    /// later with [`SeekFrom::Start`].
+    ///
+    /// # Errors
+    ///
+    /// Seeking can fail, for example when it involves flushing a buffer and
+    /// the underlying I/O encounters an error.
+    ///
+    /// If the seek operation completed successfully, this method returns the
+    /// new position from the start of the stream, otherwise it returns an
+    /// error.
+    ///
+    /// # Panics
+    ///
+    /// Calling this method without calling `start_seek` first is allowed to
+    /// panic.
+    fn poll_complete(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>>;
+}
+
+macro_rules! deref_async_seek {
+    () => {
+        fn start_seek(mut self: Pin<&mut Self>, pos: SeekFrom) -> io::Result<()> {
+            Pin::new(&mut **self).start_seek(pos)
+        }
+
+        fn poll_complete(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+            Pin::new(&mut **self).poll_complete(cx)
+        }
+    };
+}
+
+impl<T: ?Sized + AsyncSeek + Unpin> AsyncSeek for Box<T> {
+    deref_async_seek!();
+}
+
+impl<T: ?Sized + AsyncSeek + Unpin> AsyncSeek for &mut T {
+    deref_async_seek!();
+}
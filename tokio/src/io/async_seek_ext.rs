@@ -0,0 +1,36 @@
+use crate::io::util::seek::{seek, Seek};
+use crate::io::AsyncSeek;
+
+use std::io::SeekFrom;
+
+/// An extension trait which adds utility methods to [`AsyncSeek`] types.
+///
+/// This trait is automatically implemented for all types which implement
+/// [`AsyncSeek`].
+pub trait AsyncSeekExt: AsyncSeek {
+    /// Creates a future which will seek an IO object, and then yield the new
+    /// position in the stream.
+    ///
+    /// This is an async version of [`std::io::Seek::seek`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::fs::File;
+    /// use tokio::io::{self, AsyncSeekExt};
+    ///
+    /// # async fn dox() -> io::Result<()> {
+    /// let mut file = File::open("foo.txt").await?;
+    /// file.seek(io::SeekFrom::Start(6)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn seek(&mut self, pos: SeekFrom) -> Seek<'_, Self>
+    where
+        Self: Unpin,
+    {
+        seek(self, pos)
+    }
+}
+
+impl<S: AsyncSeek + ?Sized> AsyncSeekExt for S {}
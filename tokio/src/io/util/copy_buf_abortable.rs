@@ -0,0 +1,150 @@
+use crate::io::{AsyncBufRead, AsyncWrite};
+
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// Creates an [`AbortHandle`]/[`AbortRegistration`] pair that can be used to
+/// remotely cancel a [`copy_buf_abortable`] operation.
+pub fn abortable() -> (AbortHandle, AbortRegistration) {
+    let inner = Arc::new(AbortInner {
+        aborted: AtomicBool::new(false),
+        waker: Mutex::new(None),
+    });
+    (
+        AbortHandle {
+            inner: inner.clone(),
+        },
+        AbortRegistration { inner },
+    )
+}
+
+#[derive(Debug)]
+struct AbortInner {
+    aborted: AtomicBool,
+    waker: Mutex<Option<Waker>>,
+}
+
+/// A handle that can be used to remotely cancel a [`copy_buf_abortable`]
+/// operation from another task.
+#[derive(Debug, Clone)]
+pub struct AbortHandle {
+    inner: Arc<AbortInner>,
+}
+
+impl AbortHandle {
+    /// Aborts the [`copy_buf_abortable`] operation associated with this
+    /// handle's paired [`AbortRegistration`].
+    ///
+    /// This wakes the task driving the copy so it can observe the abort on
+    /// its next poll, even if it is currently parked waiting on the reader
+    /// or writer.
+    pub fn abort(&self) {
+        self.inner.aborted.store(true, Ordering::SeqCst);
+        if let Some(waker) = self.inner.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+}
+
+/// The other half of an [`AbortHandle`], passed to [`copy_buf_abortable`] to
+/// make the copy cancellable.
+#[derive(Debug)]
+pub struct AbortRegistration {
+    inner: Arc<AbortInner>,
+}
+
+/// The result of a [`copy_buf_abortable`] operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// The reader reached EOF and every byte read was written out.
+    Completed {
+        /// The total number of bytes copied from the reader to the writer.
+        bytes_copied: u64,
+    },
+    /// The copy was cancelled via [`AbortHandle::abort`] before the reader
+    /// reached EOF.
+    Aborted {
+        /// The number of bytes copied before the abort was observed.
+        bytes_copied: u64,
+    },
+}
+
+/// Asynchronously copies the entire contents of a reader into a writer, like
+/// [`copy_buf`](super::copy_buf), but can be cancelled from another task via
+/// the paired [`AbortHandle`] returned by [`abortable`].
+///
+/// Unlike simply dropping the returned future, aborting this way reports how
+/// many bytes had already been copied, which matters for resumable transfers.
+pub fn copy_buf_abortable<'a, R, W>(
+    reader: &'a mut R,
+    writer: &'a mut W,
+    abort_reg: AbortRegistration,
+) -> CopyBufAbortable<'a, R, W>
+where
+    R: AsyncBufRead + Unpin + ?Sized,
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    CopyBufAbortable {
+        reader,
+        writer,
+        abort_reg,
+        bytes_copied: 0,
+    }
+}
+
+/// Future for the [`copy_buf_abortable`] function.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct CopyBufAbortable<'a, R: ?Sized, W: ?Sized> {
+    reader: &'a mut R,
+    writer: &'a mut W,
+    abort_reg: AbortRegistration,
+    bytes_copied: u64,
+}
+
+impl<R, W> Future for CopyBufAbortable<'_, R, W>
+where
+    R: AsyncBufRead + Unpin + ?Sized,
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    type Output = io::Result<Outcome>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let me = self.get_mut();
+
+        loop {
+            // Register our waker before checking the flag so an `abort()`
+            // racing with this poll can never be missed.
+            *me.abort_reg.inner.waker.lock().unwrap() = Some(cx.waker().clone());
+            if me.abort_reg.inner.aborted.load(Ordering::SeqCst) {
+                // Flush so `bytes_copied` reflects what actually reached the
+                // writer, not what's still sitting in an internal buffer.
+                ready!(Pin::new(&mut *me.writer).poll_flush(cx))?;
+                return Poll::Ready(Ok(Outcome::Aborted {
+                    bytes_copied: me.bytes_copied,
+                }));
+            }
+
+            let available = ready!(Pin::new(&mut *me.reader).poll_fill_buf(cx))?;
+            if available.is_empty() {
+                ready!(Pin::new(&mut *me.writer).poll_flush(cx))?;
+                return Poll::Ready(Ok(Outcome::Completed {
+                    bytes_copied: me.bytes_copied,
+                }));
+            }
+
+            let n = ready!(Pin::new(&mut *me.writer).poll_write(cx, available))?;
+            if n == 0 {
+                return Poll::Ready(Err(io::Error::new(
+                    io::ErrorKind::WriteZero,
+                    "write zero byte into writer",
+                )));
+            }
+            Pin::new(&mut *me.reader).consume(n);
+            me.bytes_copied += n as u64;
+        }
+    }
+}
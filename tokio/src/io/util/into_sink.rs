@@ -0,0 +1,95 @@
+use crate::io::AsyncWrite;
+
+use bytes::Buf;
+use futures_sink::Sink;
+use pin_project::{pin_project, pinned_drop};
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+pub(super) fn into_sink<W, B>(writer: W) -> IntoSink<W, B>
+where
+    W: AsyncWrite,
+    B: Buf,
+{
+    IntoSink { writer, item: None }
+}
+
+/// Sink for the [`into_sink`](super::AsyncWriteExt::into_sink) method.
+#[pin_project(PinnedDrop)]
+#[must_use = "sinks do nothing unless polled"]
+#[cfg_attr(docsrs, doc(cfg(feature = "io-util")))]
+pub struct IntoSink<W, B> {
+    #[pin]
+    writer: W,
+    // An item accepted by `start_send` that hasn't been fully written yet.
+    item: Option<B>,
+}
+
+impl<W, B> IntoSink<W, B>
+where
+    W: AsyncWrite,
+    B: Buf,
+{
+    // Writes out as much of the pending item as the inner writer will take.
+    // Resolves once `self.item` is `None`.
+    fn poll_flush_item(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let mut this = self.as_mut().project();
+        loop {
+            match this.item {
+                Some(item) if item.has_remaining() => {
+                    let n = ready!(this.writer.as_mut().poll_write(cx, item.chunk()))?;
+                    if n == 0 {
+                        return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+                    }
+                    item.advance(n);
+                }
+                _ => {
+                    *this.item = None;
+                    return Poll::Ready(Ok(()));
+                }
+            }
+        }
+    }
+}
+
+impl<W, B> Sink<B> for IntoSink<W, B>
+where
+    W: AsyncWrite,
+    B: Buf,
+{
+    type Error = io::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.poll_flush_item(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: B) -> io::Result<()> {
+        debug_assert!(
+            self.item.is_none(),
+            "poll_ready was not called before start_send"
+        );
+        *self.project().item = Some(item);
+        Ok(())
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(self.as_mut().poll_flush_item(cx))?;
+        self.project().writer.poll_flush(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(self.as_mut().poll_flush_item(cx))?;
+        self.project().writer.poll_shutdown(cx)
+    }
+}
+
+#[pinned_drop]
+impl<W, B> PinnedDrop for IntoSink<W, B>
+where
+    W: AsyncWrite,
+{
+    fn drop(self: Pin<&mut Self>) {
+        self.project().writer.cancel_pending_writes();
+    }
+}
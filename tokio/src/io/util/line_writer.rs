@@ -0,0 +1,177 @@
+use crate::io::AsyncWrite;
+
+use memchr::memrchr;
+use pin_project::{pin_project, pinned_drop};
+use std::fmt;
+use std::io::{self, IoSlice, Write};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+const DEFAULT_BUF_SIZE: usize = 1024;
+
+/// Wraps a writer and buffers output, but flushes whenever a newline (`\n`)
+/// is written.
+///
+/// This is useful for interactive output such as a terminal, where you want
+/// output to be written as soon as a line is complete rather than waiting
+/// for an arbitrary amount of buffered data to build up.
+#[pin_project(PinnedDrop)]
+#[cfg_attr(docsrs, doc(cfg(feature = "io-util")))]
+pub struct LineWriter<W> {
+    #[pin]
+    inner: W,
+    buf: Vec<u8>,
+}
+
+impl<W: AsyncWrite> LineWriter<W> {
+    /// Creates a new `LineWriter` with a default buffer capacity.
+    pub fn new(inner: W) -> Self {
+        Self::with_capacity(DEFAULT_BUF_SIZE, inner)
+    }
+
+    /// Creates a new `LineWriter` with the specified buffer capacity.
+    pub fn with_capacity(capacity: usize, inner: W) -> Self {
+        Self {
+            inner,
+            buf: Vec::with_capacity(capacity),
+        }
+    }
+
+    fn flush_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let mut me = self.project();
+
+        let mut written = 0;
+        let len = me.buf.len();
+        let mut ret = Ok(());
+        while written < len {
+            match me.inner.as_mut().poll_write(cx, &me.buf[written..]) {
+                Poll::Pending => break,
+                Poll::Ready(Ok(0)) => {
+                    ret = Err(io::Error::new(
+                        io::ErrorKind::WriteZero,
+                        "failed to write the buffered data",
+                    ));
+                    break;
+                }
+                Poll::Ready(Ok(n)) => written += n,
+                Poll::Ready(Err(e)) => {
+                    ret = Err(e);
+                    break;
+                }
+            }
+        }
+        if written > 0 {
+            me.buf.drain(..written);
+        }
+        if ret.is_err() || written == len {
+            Poll::Ready(ret)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    /// Gets a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// It is inadvisable to directly write to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Gets a pinned mutable reference to the underlying writer.
+    ///
+    /// It is inadvisable to directly write to the underlying writer.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut W> {
+        self.project().inner
+    }
+
+    /// Consumes this `LineWriter`, returning the underlying writer.
+    ///
+    /// Note that any leftover data in the internal buffer is lost.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: AsyncWrite> AsyncWrite for LineWriter<W> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        match memrchr(b'\n', buf) {
+            Some(newline_idx) => {
+                // Drain whatever's already buffered before writing the new
+                // complete lines straight through to the inner writer.
+                ready!(self.as_mut().flush_buf(cx))?;
+
+                let (lines, rest) = buf.split_at(newline_idx + 1);
+                let me = self.as_mut().project();
+                let n = ready!(me.inner.poll_write(cx, lines))?;
+                if n < lines.len() {
+                    return Poll::Ready(Ok(n));
+                }
+
+                let me = self.project();
+                me.buf.extend_from_slice(rest);
+                Poll::Ready(Ok(lines.len() + rest.len()))
+            }
+            None => {
+                if self.buf.len() + buf.len() > self.buf.capacity() {
+                    ready!(self.as_mut().flush_buf(cx))?;
+                }
+                let me = self.project();
+                Poll::Ready(me.buf.write(buf))
+            }
+        }
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        if let Some(buf) = bufs.iter().find(|b| !b.is_empty()) {
+            self.poll_write(cx, buf)
+        } else {
+            Poll::Ready(Ok(0))
+        }
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        true
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(self.as_mut().flush_buf(cx))?;
+        self.project().inner.poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(self.as_mut().flush_buf(cx))?;
+        self.project().inner.poll_shutdown(cx)
+    }
+}
+
+impl<W: AsyncWrite + fmt::Debug> fmt::Debug for LineWriter<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LineWriter")
+            .field("writer", &self.inner)
+            .field(
+                "buffer",
+                &format_args!("{}/{}", self.buf.len(), self.buf.capacity()),
+            )
+            .finish()
+    }
+}
+
+#[pinned_drop]
+impl<W: AsyncWrite> PinnedDrop for LineWriter<W> {
+    fn drop(self: Pin<&mut Self>) {
+        self.project().inner.cancel_pending_writes();
+    }
+}
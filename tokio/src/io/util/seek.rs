@@ -0,0 +1,50 @@
+use crate::io::AsyncSeek;
+
+use std::future::Future;
+use std::io;
+use std::io::SeekFrom;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use pin_project::pin_project;
+
+pub(crate) fn seek<S>(seek: &mut S, pos: SeekFrom) -> Seek<'_, S>
+where
+    S: AsyncSeek + Unpin + ?Sized,
+{
+    Seek {
+        seek,
+        pos: Some(pos),
+        _pin: PhantomPinned,
+    }
+}
+
+/// Future for the [`seek`](crate::io::AsyncSeekExt::seek) method.
+#[pin_project]
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Seek<'a, S: ?Sized> {
+    seek: &'a mut S,
+    // `None` once the underlying `start_seek` call has been made.
+    pos: Option<SeekFrom>,
+    // Make this future `!Unpin` for compatibility with async trait methods.
+    #[pin]
+    _pin: PhantomPinned,
+}
+
+impl<S> Future for Seek<'_, S>
+where
+    S: AsyncSeek + Unpin + ?Sized,
+{
+    type Output = io::Result<u64>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let me = self.project();
+
+        if let Some(pos) = me.pos.take() {
+            Pin::new(&mut **me.seek).start_seek(pos)?;
+        }
+        Pin::new(&mut **me.seek).poll_complete(cx)
+    }
+}
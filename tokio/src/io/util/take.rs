@@ -1,6 +1,7 @@
-use crate::io::{AsyncBufRead, AsyncRead, ReadBuf};
+use crate::io::{AsyncBufRead, AsyncRead, AsyncSeek, ReadBuf};
 
 use pin_project::{pin_project, pinned_drop};
+use std::io::SeekFrom;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 use std::{cmp, io};
@@ -15,12 +16,21 @@ pub struct Take<R> where R: AsyncRead {
     inner: Option<R>,
     // Add '_' to avoid conflicts with `limit` method.
     limit_: u64,
+    // The limit this `Take` was constructed or last `set_limit`'d with, used
+    // as the fallback budget for seeks we can't adjust precisely (see
+    // `poll_complete` below).
+    original_limit: u64,
+    // The `SeekFrom` passed to the in-flight `start_seek`, stashed so
+    // `poll_complete` knows how to adjust `limit_` once the seek lands.
+    pending_seek: Option<SeekFrom>,
 }
 
 pub(super) fn take<R: AsyncRead>(inner: R, limit: u64) -> Take<R> {
     Take {
         inner: Some(inner),
         limit_: limit,
+        original_limit: limit,
+        pending_seek: None,
     }
 }
 
@@ -41,7 +51,8 @@ impl<R> Take<R> where R: AsyncRead {
     /// the amount of bytes read and the previous limit value don't matter when
     /// calling this method.
     pub fn set_limit(&mut self, limit: u64) {
-        self.limit_ = limit
+        self.limit_ = limit;
+        self.original_limit = limit;
     }
 
     /// Gets a reference to the underlying reader.
@@ -121,6 +132,47 @@ impl<R> AsyncBufRead for Take<R> where R: AsyncBufRead {
     }
 }
 
+impl<R> AsyncSeek for Take<R>
+where
+    R: AsyncRead + AsyncSeek,
+{
+    fn start_seek(self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()> {
+        let me = self.project();
+        me.inner.as_pin_mut().unwrap().start_seek(position)?;
+        *me.pending_seek = Some(position);
+        Ok(())
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        let me = self.project();
+        let pos = ready!(me.inner.as_pin_mut().unwrap().poll_complete(cx))?;
+        match me.pending_seek.take() {
+            // A relative seek moves the read cursor by a known amount, so
+            // the remaining budget can be adjusted precisely: seeking
+            // forward spends `n` bytes of it, seeking backward refunds them
+            // (capped at the original budget, since we can't refund past
+            // where this `Take`'s window started).
+            Some(SeekFrom::Current(n)) => {
+                *me.limit_ = if n >= 0 {
+                    me.limit_.saturating_sub(n as u64)
+                } else {
+                    cmp::min(*me.original_limit, me.limit_.saturating_add(n.unsigned_abs()))
+                };
+            }
+            // `Start`/`End` seek to an absolute offset, and this `Take`
+            // doesn't track where its window started in the underlying
+            // stream, so there's no way to know how much of the original
+            // budget remains at the destination. Conservatively re-arm the
+            // full budget from here, same as a fresh `take` at the new
+            // position.
+            Some(SeekFrom::Start(_) | SeekFrom::End(_)) | None => {
+                *me.limit_ = *me.original_limit;
+            }
+        }
+        Poll::Ready(Ok(pos))
+    }
+}
+
 #[pinned_drop]
 impl<R> PinnedDrop for Take<R> where R: AsyncRead {
     fn drop(self: Pin<&mut Self>) {
@@ -0,0 +1,102 @@
+//! Unix pseudoterminal (PTY) support.
+
+mod sys;
+
+pub use sys::Size;
+
+use crate::io::unix::AsyncFd;
+use crate::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+use std::io;
+use std::io::{Read, Write};
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+cfg_net_unix! {
+    /// The master side of a Unix pseudoterminal (PTY), created by [`Pty::open`].
+    ///
+    /// `Pty` registers the master file descriptor with [`AsyncFd`], so reads
+    /// and writes integrate with the reactor the same way any other
+    /// non-blocking I/O source in this crate does. Spawn a child process
+    /// attached to the slave side (obtained via [`Pty::slave_path`]) to build
+    /// a terminal multiplexer or an interactive subprocess wrapper.
+    pub struct Pty {
+        inner: AsyncFd<sys::PtyMaster>,
+    }
+}
+
+impl Pty {
+    /// Allocates a new pseudoterminal and returns its master side.
+    pub fn open() -> io::Result<Pty> {
+        let master = sys::PtyMaster::open()?;
+        Ok(Pty {
+            inner: AsyncFd::new(master)?,
+        })
+    }
+
+    /// Resizes the pseudoterminal's window.
+    pub fn resize(&self, size: Size) -> io::Result<()> {
+        self.inner.get_ref().resize(size)
+    }
+
+    /// Returns the filesystem path of the slave side of this pseudoterminal,
+    /// for spawning a child process attached to the terminal.
+    pub fn slave_path(&self) -> PathBuf {
+        self.inner.get_ref().slave_path()
+    }
+}
+
+impl AsyncRead for Pty {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        loop {
+            let mut guard = ready!(self.inner.poll_read_ready(cx))?;
+
+            let unfilled = buf.initialize_unfilled();
+            match guard.try_io(|inner| inner.get_ref().read(unfilled)) {
+                Ok(Ok(n)) => {
+                    buf.advance(n);
+                    return Poll::Ready(Ok(()));
+                }
+                Ok(Err(e)) => return Poll::Ready(Err(e)),
+                Err(_would_block) => continue,
+            }
+        }
+    }
+}
+
+impl AsyncWrite for Pty {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        loop {
+            let mut guard = ready!(self.inner.poll_write_ready(cx))?;
+
+            match guard.try_io(|inner| inner.get_ref().write(buf)) {
+                Ok(result) => return Poll::Ready(result),
+                Err(_would_block) => continue,
+            }
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsRawFd for Pty {
+    fn as_raw_fd(&self) -> RawFd {
+        self.inner.get_ref().as_raw_fd()
+    }
+}
@@ -0,0 +1,126 @@
+use std::ffi::CStr;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::os::unix::io::{AsRawFd, FromRawFd, RawFd};
+use std::path::PathBuf;
+
+/// The dimensions of a pseudoterminal window, in character cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Size {
+    /// Number of rows.
+    pub rows: u16,
+    /// Number of columns.
+    pub cols: u16,
+}
+
+#[derive(Debug)]
+pub(super) struct PtyMaster {
+    file: File,
+    slave_path: PathBuf,
+}
+
+impl PtyMaster {
+    pub(super) fn open() -> io::Result<PtyMaster> {
+        let mut master: libc::c_int = 0;
+        let mut slave: libc::c_int = 0;
+        let mut name_buf = [0 as libc::c_char; 4096];
+
+        // Safety: `openpty` writes valid, owned file descriptors into
+        // `master`/`slave` on success and a NUL-terminated path into
+        // `name_buf`, which is no smaller than `PATH_MAX` on Linux or macOS.
+        let ret = unsafe {
+            libc::openpty(
+                &mut master,
+                &mut slave,
+                name_buf.as_mut_ptr(),
+                std::ptr::null(),
+                std::ptr::null(),
+            )
+        };
+        if ret != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        // Safety: `slave` is a valid, open file descriptor that we own and
+        // are done with; the slave side is reopened by path when spawning a
+        // child process.
+        unsafe { libc::close(slave) };
+
+        set_nonblocking(master)?;
+
+        // Safety: `name_buf` was filled in by `openpty` above and is
+        // NUL-terminated.
+        let slave_path = unsafe { CStr::from_ptr(name_buf.as_ptr()) }
+            .to_string_lossy()
+            .into_owned()
+            .into();
+
+        // Safety: `master` is a valid, owned file descriptor returned by
+        // `openpty` above.
+        let file = unsafe { File::from_raw_fd(master) };
+
+        Ok(PtyMaster { file, slave_path })
+    }
+
+    pub(super) fn resize(&self, size: Size) -> io::Result<()> {
+        let winsize = libc::winsize {
+            ws_row: size.rows,
+            ws_col: size.cols,
+            ws_xpixel: 0,
+            ws_ypixel: 0,
+        };
+
+        // Safety: `winsize` is a fully initialized `libc::winsize` and the
+        // fd is valid for the lifetime of this call.
+        let ret = unsafe { libc::ioctl(self.file.as_raw_fd(), libc::TIOCSWINSZ, &winsize) };
+        if ret != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    pub(super) fn slave_path(&self) -> PathBuf {
+        self.slave_path.clone()
+    }
+}
+
+// `Read`/`Write` are implemented for `&PtyMaster`, mirroring
+// `std::fs::File`'s own `&File` impls, so that `AsyncFd`'s `try_io` closure
+// (which only hands out `&AsyncFd<PtyMaster>`) can perform the raw syscall
+// without needing a mutable borrow.
+impl Read for &PtyMaster {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        (&self.file).read(buf)
+    }
+}
+
+impl Write for &PtyMaster {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        (&self.file).write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        (&self.file).flush()
+    }
+}
+
+impl AsRawFd for PtyMaster {
+    fn as_raw_fd(&self) -> RawFd {
+        self.file.as_raw_fd()
+    }
+}
+
+fn set_nonblocking(fd: libc::c_int) -> io::Result<()> {
+    // Safety: `fd` is a valid, open file descriptor.
+    let flags = unsafe { libc::fcntl(fd, libc::F_GETFL, 0) };
+    if flags < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    // Safety: `fd` is a valid, open file descriptor and `flags` was just
+    // read from it via `F_GETFL`.
+    let ret = unsafe { libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK) };
+    if ret != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}